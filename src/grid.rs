@@ -0,0 +1,278 @@
+use crate::utils::RaggedGridError;
+use std::iter::Sum;
+
+// a rectangular grid backed by a single flat Vec, so bounds checks happen once
+// instead of being re-derived at every call site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    // errors instead of silently misaligning `row * width + col` indexing on ragged input
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedGridError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(RaggedGridError);
+        }
+
+        Ok(Grid { data: rows.into_iter().flatten().collect(), width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, row: isize, col: isize) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.height && (col as usize) < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if col >= self.width {
+            return None;
+        }
+
+        self.data.get(row * self.width + col)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if col >= self.width {
+            return None;
+        }
+
+        self.data.get_mut(row * self.width + col)
+    }
+
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        if !self.in_bounds(row, col) {
+            return None;
+        }
+
+        self.get(row as usize, col as usize)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| &self.data[row * self.width..(row + 1) * self.width])
+    }
+
+    fn neighbors_with<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        deltas.iter().filter_map(move |&(dr, dc)| {
+            let r = row as isize + dr;
+            let c = col as isize + dc;
+
+            self.get_signed(r, c).map(|value| (r as usize, c as usize, value))
+        })
+    }
+
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize, &T)> {
+        const DELTAS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        self.neighbors_with(row, col, &DELTAS)
+    }
+
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize, &T)> {
+        const DELTAS: [(isize, isize); 8] =
+            [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)];
+        self.neighbors_with(row, col, &DELTAS)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.rows().map(|row| row.to_vec()).collect()
+    }
+}
+
+impl TryFrom<&str> for Grid<char> {
+    type Error = RaggedGridError;
+
+    fn try_from(input: &str) -> Result<Self, RaggedGridError> {
+        Grid::from_rows(input.lines().map(|line| line.chars().collect()).collect())
+    }
+}
+
+//================================================================
+// Column/row summaries
+//================================================================
+
+impl<T: Sum + Copy> Grid<T> {
+    pub fn sum_column(&self, col: usize) -> Option<T> {
+        if col >= self.width {
+            return None;
+        }
+
+        Some((0..self.height).map(|row| self.data[row * self.width + col]).sum())
+    }
+
+    pub fn sum_row(&self, row: usize) -> Option<T> {
+        self.rows().nth(row).map(|cells| cells.iter().copied().sum())
+    }
+}
+
+impl<T: Ord + Copy> Grid<T> {
+    pub fn min_column(&self, col: usize) -> Option<T> {
+        if col >= self.width {
+            return None;
+        }
+
+        (0..self.height).map(|row| self.data[row * self.width + col]).min()
+    }
+
+    pub fn max_column(&self, col: usize) -> Option<T> {
+        if col >= self.width {
+            return None;
+        }
+
+        (0..self.height).map(|row| self.data[row * self.width + col]).max()
+    }
+
+    pub fn min_row(&self, row: usize) -> Option<T> {
+        self.rows().nth(row).and_then(|cells| cells.iter().copied().min())
+    }
+
+    pub fn max_row(&self, row: usize) -> Option<T> {
+        self.rows().nth(row).and_then(|cells| cells.iter().copied().max())
+    }
+}
+
+//================================================================
+// Transposition & padding
+//================================================================
+
+impl<T: Copy> Grid<T> {
+    // not named `rotate`: this swaps rows and columns (see utils::Orientation::Transpose),
+    // which is a distinct operation from the 90-degree rotations in utils::transform
+    pub fn transpose(&self) -> Grid<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+
+        for col in 0..self.width {
+            for row in 0..self.height {
+                data.push(self.data[row * self.width + col]);
+            }
+        }
+
+        Grid { data, width: self.height, height: self.width }
+    }
+
+    pub fn padded(&self, filler: T) -> Grid<T> {
+        let width = self.width + 2;
+        let mut data = Vec::with_capacity(width * (self.height + 2));
+
+        data.extend(std::iter::repeat_n(filler, width));
+        for row in self.rows() {
+            data.push(filler);
+            data.extend_from_slice(row);
+            data.push(filler);
+        }
+        data.extend(std::iter::repeat_n(filler, width));
+
+        Grid { data, width, height: self.height + 2 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<i32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap()
+    }
+
+    #[test]
+    fn grid_from_str() {
+        let grid = Grid::try_from("ab\ncd").unwrap();
+
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(1, 0), Some(&'c'));
+    }
+
+    #[test]
+    fn grid_from_rows_ragged() {
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), Err(RaggedGridError));
+        assert_eq!(Grid::try_from("ab\ncde"), Err(RaggedGridError));
+    }
+
+    #[test]
+    fn grid_get() {
+        let grid = grid();
+
+        assert_eq!(grid.get(1, 1), Some(&5));
+        assert_eq!(grid.get(5, 0), None);
+        assert_eq!(grid.get(0, 5), None);
+    }
+
+    #[test]
+    fn grid_get_signed() {
+        let grid = grid();
+
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(0, -1), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&5));
+    }
+
+    #[test]
+    fn grid_neighbors4() {
+        let grid = grid();
+        let neighbors: Vec<_> = grid.neighbors4(0, 0).map(|(r, c, &v)| (r, c, v)).collect();
+
+        assert_eq!(neighbors, vec![(1, 0, 4), (0, 1, 2)]);
+    }
+
+    #[test]
+    fn grid_neighbors8() {
+        let grid = grid();
+        let neighbors: Vec<_> = grid.neighbors8(1, 1).map(|(r, c, &v)| (r, c, v)).collect();
+
+        assert_eq!(neighbors.len(), 8);
+        assert!(neighbors.contains(&(0, 0, 1)));
+        assert!(neighbors.contains(&(2, 2, 9)));
+    }
+
+    #[test]
+    fn grid_summaries() {
+        let grid = grid();
+
+        assert_eq!(grid.sum_column(0), Some(12));
+        assert_eq!(grid.sum_row(0), Some(6));
+        assert_eq!(grid.min_column(0), Some(1));
+        assert_eq!(grid.max_column(0), Some(7));
+        assert_eq!(grid.min_row(0), Some(1));
+        assert_eq!(grid.max_row(0), Some(3));
+    }
+
+    #[test]
+    fn grid_transpose() {
+        let transposed = grid().transpose();
+
+        assert_eq!(transposed.to_rows(), vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]]);
+    }
+
+    #[test]
+    fn grid_padded() {
+        let grid = Grid::from_rows(vec![vec!['a', 'a'], vec!['a', 'a']]).unwrap();
+        let padded = grid.padded('#');
+
+        assert_eq!(
+            padded.to_rows(),
+            vec![
+                vec!['#', '#', '#', '#'],
+                vec!['#', 'a', 'a', '#'],
+                vec!['#', 'a', 'a', '#'],
+                vec!['#', '#', '#', '#'],
+            ]
+        );
+    }
+}
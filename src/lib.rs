@@ -0,0 +1,8 @@
+pub mod grid;
+pub mod parse;
+pub mod point;
+pub mod point_macro;
+pub mod prelude;
+pub mod read_tree;
+pub mod utils;
+pub mod vector;
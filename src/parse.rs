@@ -0,0 +1,99 @@
+use std::error::Error;
+use std::fs::read_to_string;
+use std::str::FromStr;
+
+//================================================================
+// Structured file parsing
+//================================================================
+
+pub fn parse_lines<T>(file_name: &str) -> Result<Vec<T>, Box<dyn Error>>
+where
+    T: FromStr,
+    T::Err: Error + 'static,
+{
+    Ok(read_to_string(file_name)?.lines().map(str::parse::<T>).collect::<Result<Vec<T>, _>>()?)
+}
+
+pub fn parse_blocks(file_name: &str) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let contents = read_to_string(file_name)?;
+
+    Ok(contents.split("\n\n").map(|block| block.lines().map(str::to_string).collect()).collect())
+}
+
+pub fn parse_grid_of<T>(file_name: &str, mut convert: impl FnMut(char) -> T) -> Result<Vec<Vec<T>>, Box<dyn Error>> {
+    let contents = read_to_string(file_name)?;
+
+    Ok(contents.lines().map(|line| line.chars().map(&mut convert).collect()).collect())
+}
+
+//================================================================
+// Record helpers
+//================================================================
+
+pub fn chunks<T: Clone>(records: &[T], size: usize) -> Vec<Vec<T>> {
+    records.chunks(size).map(<[T]>::to_vec).collect()
+}
+
+pub fn windows<T: Clone>(records: &[T], size: usize) -> Vec<Vec<T>> {
+    records.windows(size).map(<[T]>::to_vec).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{remove_file, write};
+
+    fn create_input(path: &str, contents: &str) {
+        write(path, contents).expect("Failed to create input file");
+    }
+
+    fn remove_input(path: &str) {
+        remove_file(path).expect("Failed to delete input file");
+    }
+
+    #[test]
+    fn lines() {
+        let path = "parse_lines.txt";
+        create_input(path, "1\n2\n3");
+
+        assert_eq!(parse_lines::<i32>(path).unwrap(), vec![1, 2, 3]);
+
+        remove_input(path);
+    }
+
+    #[test]
+    fn blocks() {
+        let path = "parse_blocks.txt";
+        create_input(path, "1\n2\n\n3\n4");
+
+        assert_eq!(
+            parse_blocks(path).unwrap(),
+            vec![vec!["1".to_string(), "2".to_string()], vec!["3".to_string(), "4".to_string()]]
+        );
+
+        remove_input(path);
+    }
+
+    #[test]
+    fn grid_of() {
+        let path = "parse_grid.txt";
+        create_input(path, "12\n34");
+
+        assert_eq!(
+            parse_grid_of(path, |c| c.to_digit(10).unwrap()).unwrap(),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+
+        remove_input(path);
+    }
+
+    #[test]
+    fn chunks_of_records() {
+        assert_eq!(chunks(&[1, 2, 3, 4], 2), vec![vec![1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn windows_of_records() {
+        assert_eq!(windows(&[1, 2, 3], 2), vec![vec![1, 2], vec![2, 3]]);
+    }
+}
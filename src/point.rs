@@ -1,9 +1,13 @@
-use num_traits::{One, Signed, Unsigned, Zero};
+use crate::vector::Vector;
+use num_traits::{Euclid, Float, NumCast, One, Signed, ToPrimitive, Unsigned, Zero};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::cmp::{max, Ord};
 use std::fmt::{Display, Formatter, Result};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, Sub, SubAssign};
 
 #[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Point<T> {
     pub x: T,
     pub y: T,
@@ -21,6 +25,56 @@ impl<T: Default> Default for Point<T> {
     }
 }
 
+//================================================================
+// Tuple/array conversions
+//================================================================
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: PartialEq> PartialEq<(T, T)> for Point<T> {
+    fn eq(&self, (x, y): &(T, T)) -> bool {
+        self.x == *x && self.y == *y
+    }
+}
+
+impl<T: PartialEq> PartialEq<Point<T>> for (T, T) {
+    fn eq(&self, point: &Point<T>) -> bool {
+        self.0 == point.x && self.1 == point.y
+    }
+}
+
+//================================================================
+// Mapping and casting
+//================================================================
+
+impl<T> Point<T> {
+    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Point<R> {
+        Point::new(f(self.x), f(self.y))
+    }
+}
+
+impl<T: ToPrimitive> Point<T> {
+    pub fn cast<U: NumCast>(self) -> Option<Point<U>> {
+        Some(Point::new(U::from(self.x)?, U::from(self.y)?))
+    }
+}
+
 //================================================================
 // Printing
 //================================================================
@@ -43,11 +97,12 @@ impl<T: Add<Output = T>> Add for Point<T> {
     }
 }
 
+// subtracting two positions yields the displacement between them
 impl<T: Sub<Output = T>> Sub for Point<T> {
-    type Output = Point<T>;
+    type Output = Vector<T>;
 
-    fn sub(self, rhs: Point<T>) -> Point<T> {
-        Point::new(self.x - rhs.x, self.y - rhs.y)
+    fn sub(self, rhs: Point<T>) -> Vector<T> {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
@@ -149,6 +204,255 @@ impl<T: Add<Output = T> + Sub<Output = T> + One + Copy> Point<T> {
     }
 }
 
+impl<T: Add<Output = T> + Sub<Output = T> + One + Copy> Point<T> {
+    pub fn up_left(&self) -> Point<T> {
+        Point::new(self.x - T::one(), self.y - T::one())
+    }
+
+    pub fn up_right(&self) -> Point<T> {
+        Point::new(self.x + T::one(), self.y - T::one())
+    }
+
+    pub fn down_left(&self) -> Point<T> {
+        Point::new(self.x - T::one(), self.y + T::one())
+    }
+
+    pub fn down_right(&self) -> Point<T> {
+        Point::new(self.x + T::one(), self.y + T::one())
+    }
+
+    pub fn neighbors8(&self) -> [Point<T>; 8] {
+        [
+            self.up(),
+            self.down(),
+            self.left(),
+            self.right(),
+            self.up_left(),
+            self.up_right(),
+            self.down_left(),
+            self.down_right(),
+        ]
+    }
+}
+
+impl<T: Add<Output = T> + Zero + One + Sub<Output = T> + Copy> Point<T> {
+    pub fn step(&self, dir: Direction) -> Point<T> {
+        *self + dir.offset()
+    }
+}
+
+impl<T: AddAssign + Zero + One + Sub<Output = T> + Copy> Point<T> {
+    pub fn step_mut(&mut self, dir: Direction) {
+        *self += dir.offset();
+    }
+}
+
+//================================================================
+// Direction
+//================================================================
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    // unit offset for this direction, expressed in whatever coordinate type the caller needs
+    pub fn offset<T>(&self) -> Point<T>
+    where
+        T: Zero + One + Sub<Output = T> + Copy,
+    {
+        let zero = T::zero();
+        let one = T::one();
+        let neg_one = zero - one;
+
+        match self {
+            Direction::Up => Point::new(zero, neg_one),
+            Direction::Down => Point::new(zero, one),
+            Direction::Left => Point::new(neg_one, zero),
+            Direction::Right => Point::new(one, zero),
+            Direction::UpLeft => Point::new(neg_one, neg_one),
+            Direction::UpRight => Point::new(one, neg_one),
+            Direction::DownLeft => Point::new(neg_one, one),
+            Direction::DownRight => Point::new(one, one),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+            Direction::UpLeft => Direction::DownLeft,
+            Direction::DownLeft => Direction::DownRight,
+            Direction::DownRight => Direction::UpRight,
+            Direction::UpRight => Direction::UpLeft,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::UpRight => Direction::DownRight,
+            Direction::DownRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpLeft,
+            Direction::UpLeft => Direction::UpRight,
+        }
+    }
+}
+
+//================================================================
+// Bounded navigation
+//================================================================
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Boundary {
+    Clamp,
+    Wrap,
+}
+
+// an inclusive rectangular region, used to confine a Point to a grid
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct Bounds<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T> Bounds<T> {
+    pub fn new(min: Point<T>, max: Point<T>) -> Self {
+        Bounds { min, max }
+    }
+}
+
+impl<T: Ord + Copy> Bounds<T> {
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+    }
+}
+
+impl<T: Add<Output = T> + One + Ord + Copy> Bounds<T> {
+    pub fn iter(&self) -> BoundsIter<T> {
+        // an inverted/empty region (min > max) yields no points, matching `contains`
+        let next = if self.min.x <= self.max.x && self.min.y <= self.max.y { Some(self.min) } else { None };
+
+        BoundsIter { bounds: *self, next }
+    }
+}
+
+pub struct BoundsIter<T> {
+    bounds: Bounds<T>,
+    next: Option<Point<T>>,
+}
+
+impl<T: Add<Output = T> + One + Ord + Copy> Iterator for BoundsIter<T> {
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Point<T>> {
+        let current = self.next?;
+
+        let mut next = current;
+        next.x = next.x + T::one();
+        if next.x > self.bounds.max.x {
+            next.x = self.bounds.min.x;
+            next.y = next.y + T::one();
+        }
+
+        self.next = if next.y > self.bounds.max.y { None } else { Some(next) };
+
+        Some(current)
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T> + Zero + One + Ord + Euclid + Copy,
+{
+    pub fn stepped(&self, dir: Direction, bounds: &Bounds<T>, boundary: Boundary) -> Point<T> {
+        let moved = self.step(dir);
+
+        match boundary {
+            Boundary::Clamp => {
+                Point::new(moved.x.clamp(bounds.min.x, bounds.max.x), moved.y.clamp(bounds.min.y, bounds.max.y))
+            }
+            Boundary::Wrap => {
+                let width_x = bounds.max.x - bounds.min.x + T::one();
+                let width_y = bounds.max.y - bounds.min.y + T::one();
+
+                Point::new(
+                    bounds.min.x + (moved.x - bounds.min.x).rem_euclid(&width_x),
+                    bounds.min.y + (moved.y - bounds.min.y).rem_euclid(&width_y),
+                )
+            }
+        }
+    }
+}
+
+//================================================================
+// Floating-point geometry
+//================================================================
+
+impl<T: Float> Point<T> {
+    pub fn distance(self, other: Point<T>) -> T {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    pub fn distance_squared(self, other: Point<T>) -> T {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+
+        dx * dx + dy * dy
+    }
+
+    pub fn magnitude(self) -> T {
+        self.x.hypot(self.y)
+    }
+
+    pub fn magnitude_squared(self) -> T {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn normalized(self) -> Point<T> {
+        let magnitude = self.magnitude();
+        Point::new(self.x / magnitude, self.y / magnitude)
+    }
+
+    pub fn lerp(self, other: Point<T>, t: T) -> Point<T> {
+        Point::new(self.x + (other.x - self.x) * t, self.y + (other.y - self.y) * t)
+    }
+
+    pub fn midpoint(self, other: Point<T>) -> Point<T> {
+        self.lerp(other, T::from(0.5).unwrap())
+    }
+
+    pub fn angle(self) -> T {
+        self.y.atan2(self.x)
+    }
+}
+
 //================================================================
 // Other related functions
 //================================================================
@@ -219,6 +523,34 @@ mod tests {
         assert_ne!(p1, p4);
     }
 
+    #[test]
+    fn point_tuple_array_conversions() {
+        let p = Point::new(1, 2);
+
+        assert_eq!(Point::from((1, 2)), p);
+        assert_eq!(Point::from([1, 2]), p);
+        assert_eq!(<(i32, i32)>::from(p), (1, 2));
+
+        assert_eq!(p, (1, 2));
+        assert_eq!((1, 2), p);
+    }
+
+    #[test]
+    fn point_map() {
+        let p = Point::new(1, 2);
+
+        assert_eq!(p.map(|n| n * 2), Point::new(2, 4));
+    }
+
+    #[test]
+    fn point_cast() {
+        let p = Point::new(3i64, -1i64);
+
+        assert_eq!(p.cast::<usize>(), None);
+        assert_eq!(Point::new(3i64, 4i64).cast::<usize>(), Some(Point::new(3usize, 4usize)));
+        assert_eq!(Point::new(3i64, 4i64).cast::<f64>(), Some(Point::new(3.0, 4.0)));
+    }
+
     #[test]
     fn point_order() {
         let p1 = Point::new(1, 2);
@@ -252,7 +584,7 @@ mod tests {
         let p2 = Point::new(3, 4);
         let p3 = Point::new(-2, -2);
 
-        assert_eq!(p1 - p2, p3);
+        assert_eq!(p1 - p2, Vector::new(-2, -2));
         assert_ne!(p1 - p2, p2 - p1);
 
         p1 -= p2;
@@ -331,6 +663,170 @@ mod tests {
         assert_eq!(p1, result);
     }
 
+    #[test]
+    fn point_diagonals() {
+        let p1 = Point::new(1, 2);
+
+        assert_eq!(p1.up_left(), Point::new(0, 1));
+        assert_eq!(p1.up_right(), Point::new(2, 1));
+        assert_eq!(p1.down_left(), Point::new(0, 3));
+        assert_eq!(p1.down_right(), Point::new(2, 3));
+    }
+
+    #[test]
+    fn point_neighbors8() {
+        let p1 = Point::new(1, 2);
+
+        assert_eq!(
+            p1.neighbors8(),
+            [
+                Point::new(1, 1),
+                Point::new(1, 3),
+                Point::new(0, 2),
+                Point::new(2, 2),
+                Point::new(0, 1),
+                Point::new(2, 1),
+                Point::new(0, 3),
+                Point::new(2, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn point_step() {
+        let mut p1 = Point::new(1, 2);
+        let result = Point::new(2, 1);
+
+        assert_eq!(p1.step(Direction::UpRight), result);
+
+        p1.step_mut(Direction::UpRight);
+        assert_eq!(p1, result);
+    }
+
+    #[test]
+    fn direction_offset() {
+        assert_eq!(Direction::Up.offset::<i32>(), Point::new(0, -1));
+        assert_eq!(Direction::DownRight.offset::<i32>(), Point::new(1, 1));
+    }
+
+    #[test]
+    fn direction_opposite() {
+        assert_eq!(Direction::Up.opposite(), Direction::Down);
+        assert_eq!(Direction::UpLeft.opposite(), Direction::DownRight);
+    }
+
+    #[test]
+    fn direction_turn() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::UpRight.turn_left(), Direction::UpLeft);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+    }
+
+    #[test]
+    fn bounds_contains() {
+        let bounds = Bounds::new(Point::new(0, 0), Point::new(2, 2));
+
+        assert!(bounds.contains(&Point::new(0, 0)));
+        assert!(bounds.contains(&Point::new(2, 2)));
+        assert!(!bounds.contains(&Point::new(3, 0)));
+        assert!(!bounds.contains(&Point::new(0, -1)));
+    }
+
+    #[test]
+    fn bounds_iter() {
+        let bounds = Bounds::new(Point::new(0, 0), Point::new(1, 1));
+
+        assert_eq!(
+            bounds.iter().collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn bounds_iter_empty_when_inverted() {
+        let bounds = Bounds::new(Point::new(5, 5), Point::new(0, 0));
+
+        assert_eq!(bounds.iter().collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn point_stepped_clamp() {
+        let bounds = Bounds::new(Point::new(0, 0), Point::new(2, 2));
+        let p = Point::new(0, 0);
+
+        assert_eq!(p.stepped(Direction::Up, &bounds, Boundary::Clamp), Point::new(0, 0));
+        assert_eq!(p.stepped(Direction::Right, &bounds, Boundary::Clamp), Point::new(1, 0));
+    }
+
+    #[test]
+    fn point_stepped_wrap() {
+        let bounds = Bounds::new(Point::new(0, 0), Point::new(2, 2));
+        let p = Point::new(0, 0);
+
+        assert_eq!(p.stepped(Direction::Up, &bounds, Boundary::Wrap), Point::new(0, 2));
+        assert_eq!(Point::new(2, 0).stepped(Direction::Right, &bounds, Boundary::Wrap), Point::new(0, 0));
+    }
+
+    #[test]
+    fn point_distance() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(3.0, 4.0);
+
+        assert_eq!(p1.distance(p2), 5.0);
+        assert_eq!(p1.distance_squared(p2), 25.0);
+    }
+
+    #[test]
+    fn point_magnitude() {
+        let p = Point::new(3.0, 4.0);
+
+        assert_eq!(p.magnitude(), 5.0);
+        assert_eq!(p.magnitude_squared(), 25.0);
+    }
+
+    #[test]
+    fn point_normalized() {
+        let p = Point::new(3.0, 4.0).normalized();
+
+        assert_eq!(p, Point::new(0.6, 0.8));
+        assert_eq!(p.magnitude(), 1.0);
+    }
+
+    #[test]
+    fn point_lerp_midpoint() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(10.0, 20.0);
+
+        assert_eq!(p1.lerp(p2, 0.5), Point::new(5.0, 10.0));
+        assert_eq!(p1.midpoint(p2), Point::new(5.0, 10.0));
+    }
+
+    #[test]
+    fn point_angle() {
+        assert_eq!(Point::new(1.0, 0.0).angle(), 0.0);
+        assert_eq!(Point::new(0.0, 1.0).angle(), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn point_serde_roundtrip_int() {
+        let p = Point::new(1, 2);
+        let json = serde_json::to_string(&p).unwrap();
+
+        assert_eq!(json, r#"{"x":1,"y":2}"#);
+        assert_eq!(serde_json::from_str::<Point<i32>>(&json).unwrap(), p);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn point_serde_roundtrip_float() {
+        let p = Point::new(1.5, 2.5);
+        let json = serde_json::to_string(&p).unwrap();
+
+        assert_eq!(serde_json::from_str::<Point<f64>>(&json).unwrap(), p);
+    }
+
     #[test]
     fn point_manhattan() {
         let p1 = Point::new(1, 2);
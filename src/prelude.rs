@@ -0,0 +1,11 @@
+// the most-used functions and types, re-exported so downstream code can
+// `use rust_utils::prelude::*` instead of importing each symbol individually
+
+pub use crate::grid::Grid;
+pub use crate::parse::{chunks, parse_blocks, parse_grid_of, parse_lines, windows};
+pub use crate::point::{Boundary, Bounds, Direction, Point};
+pub use crate::utils::{
+    filter_by_regex, filter_input, modulus, pad, parse_size, rotate, split_2d_by_regex, split_2d_input,
+    split_by_regex, split_input, to_2d_numeric, to_numeric, transform, Orientation, RaggedGridError,
+};
+pub use crate::vector::Vector;
@@ -1,5 +1,10 @@
+use crate::grid::Grid;
+use crate::point::Direction;
 use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
 use std::fs::read_to_string;
+use std::hash::Hash;
 use std::iter::Sum;
 use std::ops::{Add, Rem};
 use std::str::FromStr;
@@ -77,18 +82,25 @@ where
 // Grid utilities
 //================================================================
 
+// thin wrapper kept for callers still passing loose nested slices; builds a Grid and
+// delegates so the bounds-checking logic only lives in one place. `None` on ragged input,
+// matching the `Option`-returning summaries below rather than surfacing `RaggedGridError`.
+fn to_grid<A, B, T>(grid: A) -> Option<Grid<T>>
+where
+    A: AsRef<[B]>,
+    B: AsRef<[T]>,
+    T: Copy,
+{
+    Grid::from_rows(grid.as_ref().iter().map(|row| row.as_ref().to_vec()).collect()).ok()
+}
+
 pub fn sum_column<A, B, T>(grid: A, n: usize) -> Option<T>
 where
     A: AsRef<[B]>,
     B: AsRef<[T]>,
     T: Sum + Copy,
 {
-    let grid_ref = grid.as_ref();
-    if grid_ref.is_empty() || n >= grid_ref[0].as_ref().len() {
-        return None;
-    }
-
-    Some(grid_ref.iter().map(|row| row.as_ref()[n]).sum())
+    to_grid(grid)?.sum_column(n)
 }
 
 pub fn sum_row<A, B, T>(grid: A, n: usize) -> Option<T>
@@ -97,7 +109,7 @@ where
     B: AsRef<[T]>,
     T: Sum + Copy,
 {
-    Some(grid.as_ref().get(n)?.as_ref().iter().copied().sum())
+    to_grid(grid)?.sum_row(n)
 }
 
 pub fn min_column<A, B, T>(grid: A, n: usize) -> Option<T>
@@ -106,11 +118,7 @@ where
     B: AsRef<[T]>,
     T: Ord + Copy,
 {
-    let grid_ref = grid.as_ref();
-    if grid_ref.is_empty() || n >= grid_ref[0].as_ref().len() {
-        return None;
-    }
-    grid_ref.iter().map(|row| row.as_ref()[n]).min()
+    to_grid(grid)?.min_column(n)
 }
 
 pub fn max_column<A, B, T>(grid: A, n: usize) -> Option<T>
@@ -119,11 +127,7 @@ where
     B: AsRef<[T]>,
     T: Ord + Copy,
 {
-    let grid_ref = grid.as_ref();
-    if grid_ref.is_empty() || n >= grid_ref[0].as_ref().len() {
-        return None;
-    }
-    grid_ref.iter().map(|row| row.as_ref()[n]).max()
+    to_grid(grid)?.max_column(n)
 }
 
 pub fn min_row<A, B, T>(grid: A, n: usize) -> Option<T>
@@ -132,7 +136,7 @@ where
     B: AsRef<[T]>,
     T: Ord + Copy,
 {
-    grid.as_ref().get(n)?.as_ref().iter().copied().min()
+    to_grid(grid)?.min_row(n)
 }
 
 pub fn max_row<A, B, T>(grid: A, n: usize) -> Option<T>
@@ -141,26 +145,188 @@ where
     B: AsRef<[T]>,
     T: Ord + Copy,
 {
-    grid.as_ref().get(n)?.as_ref().iter().copied().max()
+    to_grid(grid)?.max_row(n)
+}
+
+//================================================================
+// Orientation transforms
+//================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Rotate90CW,
+    Rotate90CCW,
+    Rotate180,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedGridError;
+
+impl fmt::Display for RaggedGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "grid rows have differing lengths")
+    }
 }
 
-pub fn rotate<A, B, T>(grid: A) -> Vec<Vec<T>>
+impl std::error::Error for RaggedGridError {}
+
+// applies `orientation` to `grid`, erroring instead of panicking when rows are uneven
+pub fn transform<A, B, T>(grid: A, orientation: Orientation) -> Result<Vec<Vec<T>>, RaggedGridError>
 where
     A: AsRef<[B]>,
     B: AsRef<[T]>,
     T: Copy,
 {
-    let max_column_size = grid.as_ref().iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let grid_ref = grid.as_ref();
+    let height = grid_ref.len();
+    let width = grid_ref.first().map_or(0, |row| row.as_ref().len());
 
-    let mut rotated: Vec<Vec<T>> = vec![Vec::new(); max_column_size];
+    if grid_ref.iter().any(|row| row.as_ref().len() != width) {
+        return Err(RaggedGridError);
+    }
+
+    let cell = |row: usize, col: usize| grid_ref[row].as_ref()[col];
 
-    for row in grid.as_ref() {
-        for (i, &element) in row.as_ref().iter().enumerate() {
-            rotated[i].push(element);
+    Ok(match orientation {
+        Orientation::Rotate90CW => {
+            (0..width).map(|col| (0..height).rev().map(|row| cell(row, col)).collect()).collect()
+        }
+        Orientation::Rotate90CCW => {
+            (0..width).rev().map(|col| (0..height).map(|row| cell(row, col)).collect()).collect()
+        }
+        Orientation::Rotate180 => {
+            (0..height).rev().map(|row| (0..width).rev().map(|col| cell(row, col)).collect()).collect()
+        }
+        Orientation::FlipHorizontal => {
+            (0..height).map(|row| (0..width).rev().map(|col| cell(row, col)).collect()).collect()
+        }
+        Orientation::FlipVertical => {
+            (0..height).rev().map(|row| (0..width).map(|col| cell(row, col)).collect()).collect()
+        }
+        Orientation::Transpose => {
+            (0..width).map(|col| (0..height).map(|row| cell(row, col)).collect()).collect()
+        }
+    })
+}
+
+// kept as an alias for the common case of a single clockwise quarter-turn
+pub fn rotate<A, B, T>(grid: A) -> Result<Vec<Vec<T>>, RaggedGridError>
+where
+    A: AsRef<[B]>,
+    B: AsRef<[T]>,
+    T: Copy,
+{
+    transform(grid, Orientation::Rotate90CW)
+}
+
+//================================================================
+// Grid tilting & cycle detection
+//================================================================
+
+// compacts every 'O' in `lane` toward index 0, stopping at '#' walls or other rocks
+fn compact_toward_start(lane: &mut [char]) {
+    let mut free = 0;
+
+    for i in 0..lane.len() {
+        match lane[i] {
+            '#' => free = i + 1,
+            'O' => {
+                lane[i] = '.';
+                lane[free] = 'O';
+                free += 1;
+            }
+            _ => {}
         }
     }
+}
+
+fn compact_toward_end(lane: &mut [char]) {
+    lane.reverse();
+    compact_toward_start(lane);
+    lane.reverse();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonCardinalDirectionError;
 
-    rotated
+impl fmt::Display for NonCardinalDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "tilt only supports the four cardinal directions")
+    }
+}
+
+impl std::error::Error for NonCardinalDirectionError {}
+
+// slides every 'O' rock in `grid` toward `direction`, stopping at '#' walls or other rocks
+pub fn tilt(grid: &mut [Vec<char>], direction: Direction) -> Result<(), NonCardinalDirectionError> {
+    let height = grid.len();
+    let width = grid.first().map_or(0, |row| row.len());
+
+    match direction {
+        Direction::Up | Direction::Down => {
+            let mut lanes: Vec<Vec<char>> =
+                (0..width).map(|col| (0..height).map(|row| grid[row][col]).collect()).collect();
+
+            for lane in &mut lanes {
+                if direction == Direction::Up {
+                    compact_toward_start(lane);
+                } else {
+                    compact_toward_end(lane);
+                }
+            }
+
+            for (col, lane) in lanes.iter().enumerate() {
+                for (row, &cell) in lane.iter().enumerate() {
+                    grid[row][col] = cell;
+                }
+            }
+        }
+        Direction::Left => {
+            for row in grid.iter_mut() {
+                compact_toward_start(row);
+            }
+        }
+        Direction::Right => {
+            for row in grid.iter_mut() {
+                compact_toward_end(row);
+            }
+        }
+        _ => return Err(NonCardinalDirectionError),
+    }
+
+    Ok(())
+}
+
+// repeatedly applies `step` to `initial`, detecting a cycle in the visited states so that
+// `iterations` applications can be fast-forwarded without actually running them all
+pub fn fixed_point_cycle<S, F>(initial: S, mut step: F, iterations: usize) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+
+    for j in 0..iterations {
+        if let Some(&i) = seen.get(&state) {
+            let cycle_len = j - i;
+            let remaining = (iterations - i) % cycle_len;
+
+            for _ in 0..remaining {
+                state = step(state);
+            }
+
+            return state;
+        }
+
+        seen.insert(state.clone(), j);
+        state = step(state);
+    }
+
+    state
 }
 
 pub fn pad(input: &str, filler: char) -> Option<Vec<Vec<char>>> {
@@ -170,24 +336,7 @@ pub fn pad(input: &str, filler: char) -> Option<Vec<Vec<char>>> {
         return None;
     }
 
-    let padded_lines: Vec<Vec<char>> = lines
-        .iter()
-        .map(|line| {
-            let mut chars: Vec<char> = line.chars().collect();
-            chars.insert(0, filler); // Add 'X' at the beginning
-            chars.push(filler); // Add 'X' at the end
-            chars
-        })
-        .collect();
-
-    let border_width = padded_lines[0].len();
-    let border: Vec<char> = std::iter::repeat_n(filler, border_width).collect();
-
-    let mut result = vec![border.clone()];
-    result.extend(padded_lines);
-    result.push(border);
-
-    Some(result)
+    Some(Grid::try_from(input).ok()?.padded(filler).to_rows())
 }
 
 //================================================================
@@ -201,6 +350,50 @@ where
     ((lhs % rhs) + rhs) % rhs
 }
 
+//================================================================
+// Human-readable size parsing
+//================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSizeError {
+    Empty,
+    InvalidNumber(String),
+}
+
+impl fmt::Display for ParseSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseSizeError::Empty => write!(f, "cannot parse size from an empty string"),
+            ParseSizeError::InvalidNumber(s) => write!(f, "'{s}' is not a valid size"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSizeError {}
+
+// parses a byte count with an optional k/m/g suffix (binary magnitudes: 2^10/2^20/2^30);
+// a bare number is treated as a byte count
+pub fn parse_size(input: &str) -> Result<u64, ParseSizeError> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err(ParseSizeError::Empty);
+    }
+
+    let last = input.chars().last().unwrap();
+
+    let (digits, magnitude) = match last.to_ascii_lowercase() {
+        'k' => (&input[..input.len() - last.len_utf8()], 1u64 << 10),
+        'm' => (&input[..input.len() - last.len_utf8()], 1u64 << 20),
+        'g' => (&input[..input.len() - last.len_utf8()], 1u64 << 30),
+        _ => (input, 1),
+    };
+
+    let value: u64 = digits.parse().map_err(|_| ParseSizeError::InvalidNumber(input.to_string()))?;
+
+    Ok(value * magnitude)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,7 +489,68 @@ mod tests {
     fn grid_rotate() {
         let grid = &[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]];
 
-        assert_eq!(rotate(grid), vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]])
+        assert_eq!(rotate(grid).unwrap(), vec![vec![7, 4, 1], vec![8, 5, 2], vec![9, 6, 3]])
+    }
+
+    #[test]
+    fn grid_transform_orientations() {
+        let grid = &[&[1, 2], &[3, 4]];
+
+        assert_eq!(transform(grid, Orientation::Rotate90CW).unwrap(), vec![vec![3, 1], vec![4, 2]]);
+        assert_eq!(transform(grid, Orientation::Rotate90CCW).unwrap(), vec![vec![2, 4], vec![1, 3]]);
+        assert_eq!(transform(grid, Orientation::Rotate180).unwrap(), vec![vec![4, 3], vec![2, 1]]);
+        assert_eq!(transform(grid, Orientation::FlipHorizontal).unwrap(), vec![vec![2, 1], vec![4, 3]]);
+        assert_eq!(transform(grid, Orientation::FlipVertical).unwrap(), vec![vec![3, 4], vec![1, 2]]);
+        assert_eq!(transform(grid, Orientation::Transpose).unwrap(), vec![vec![1, 3], vec![2, 4]]);
+    }
+
+    #[test]
+    fn grid_transform_ragged_rows() {
+        let grid = &[&[1, 2, 3][..], &[4, 5][..]];
+
+        assert_eq!(transform(grid, Orientation::Rotate90CW), Err(RaggedGridError));
+    }
+
+    #[test]
+    fn tilt_north_south() {
+        let mut grid = vec![vec!['.', 'O'], vec!['O', '#'], vec!['.', 'O']];
+
+        tilt(&mut grid, Direction::Up).unwrap();
+        assert_eq!(grid, vec![vec!['O', 'O'], vec!['.', '#'], vec!['.', 'O']]);
+
+        tilt(&mut grid, Direction::Down).unwrap();
+        assert_eq!(grid, vec![vec!['.', 'O'], vec!['.', '#'], vec!['O', 'O']]);
+    }
+
+    #[test]
+    fn tilt_east_west() {
+        let mut grid = vec![vec!['O', '.', '#', '.', 'O']];
+
+        tilt(&mut grid, Direction::Left).unwrap();
+        assert_eq!(grid, vec![vec!['O', '.', '#', 'O', '.']]);
+
+        tilt(&mut grid, Direction::Right).unwrap();
+        assert_eq!(grid, vec![vec!['.', 'O', '#', '.', 'O']]);
+    }
+
+    #[test]
+    fn tilt_rejects_diagonal_direction() {
+        let mut grid = vec![vec!['.', 'O']];
+
+        assert_eq!(tilt(&mut grid, Direction::UpLeft), Err(NonCardinalDirectionError));
+    }
+
+    #[test]
+    fn cycle_fast_forward() {
+        // state cycles 0 -> 1 -> 2 -> 0 -> ...
+        let state = fixed_point_cycle(0, |n| (n + 1) % 3, 1_000_000_000);
+        assert_eq!(state, 1_000_000_000 % 3);
+    }
+
+    #[test]
+    fn cycle_before_repeat() {
+        let state = fixed_point_cycle(0, |n| n + 1, 5);
+        assert_eq!(state, 5);
     }
 
     #[test]
@@ -308,6 +562,22 @@ mod tests {
         assert_eq!(modulus(b, 5), 3);
     }
 
+    #[test]
+    fn size_parsing() {
+        assert_eq!(parse_size("1024"), Ok(1024));
+        assert_eq!(parse_size("1k"), Ok(1 << 10));
+        assert_eq!(parse_size("2K"), Ok(2 << 10));
+        assert_eq!(parse_size("1m"), Ok(1 << 20));
+        assert_eq!(parse_size("1g"), Ok(1 << 30));
+    }
+
+    #[test]
+    fn size_parsing_errors() {
+        assert_eq!(parse_size(""), Err(ParseSizeError::Empty));
+        assert_eq!(parse_size("abc"), Err(ParseSizeError::InvalidNumber("abc".to_string())));
+        assert_eq!(parse_size("1µ"), Err(ParseSizeError::InvalidNumber("1µ".to_string())));
+    }
+
     #[test]
     fn padding() {
         assert_eq!(
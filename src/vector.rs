@@ -0,0 +1,204 @@
+use crate::point::Point;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// a displacement, as opposed to Point's affine position
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector { x, y }
+    }
+}
+
+impl<T: Default> Default for Vector<T> {
+    fn default() -> Self {
+        Vector::new(T::default(), T::default())
+    }
+}
+
+//================================================================
+// Printing
+//================================================================
+
+impl<T: Display> Display for Vector<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+//================================================================
+// Conversions to/from Point
+//================================================================
+
+impl<T> From<Vector<T>> for Point<T> {
+    fn from(vector: Vector<T>) -> Self {
+        Point::new(vector.x, vector.y)
+    }
+}
+
+impl<T> From<Point<T>> for Vector<T> {
+    fn from(point: Point<T>) -> Self {
+        Vector::new(point.x, point.y)
+    }
+}
+
+//================================================================
+// Operator overloads
+//================================================================
+
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<U, Output = T>, U: Copy> Mul<U> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: U) -> Vector<T> {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vector<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vector<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: MulAssign<U>, U: Copy> MulAssign<U> for Vector<T> {
+    fn mul_assign(&mut self, rhs: U) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+//================================================================
+// Dot, cross, and rotation
+//================================================================
+
+impl<T: Mul<Output = T> + Add<Output = T> + Copy> Vector<T> {
+    pub fn dot(self, rhs: Vector<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+}
+
+impl<T: Mul<Output = T> + Sub<Output = T> + Copy> Vector<T> {
+    // 2D cross product (the z-component of the 3D cross product), useful for
+    // orientation/turn tests and the shoelace formula
+    pub fn cross(self, rhs: Vector<T>) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+}
+
+impl<T: Neg<Output = T> + Copy> Vector<T> {
+    pub fn perp(self) -> Vector<T> {
+        Vector::new(-self.y, self.x)
+    }
+
+    // same rotation as `perp`; kept as a separate name for callers thinking in terms of
+    // clockwise/counter-clockwise rather than the perpendicular vector
+    pub fn rotate_cw(self) -> Vector<T> {
+        self.perp()
+    }
+
+    pub fn rotate_ccw(self) -> Vector<T> {
+        Vector::new(self.y, -self.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_print() {
+        let v = Vector::new(1, 2);
+        assert_eq!(v.to_string(), "(1, 2)");
+    }
+
+    #[test]
+    fn vector_point_conversions() {
+        let p = Point::new(1, 2);
+        let v = Vector::new(1, 2);
+
+        assert_eq!(Vector::from(p), v);
+        assert_eq!(Point::from(v), p);
+        assert_eq!(Point::new(3, 4) - Point::new(1, 2), Vector::new(2, 2));
+    }
+
+    #[test]
+    fn vector_add_subtract() {
+        let mut v1 = Vector::new(1, 2);
+        let v2 = Vector::new(3, 4);
+
+        assert_eq!(v1 + v2, Vector::new(4, 6));
+        assert_eq!(v2 - v1, Vector::new(2, 2));
+
+        v1 += v2;
+        assert_eq!(v1, Vector::new(4, 6));
+
+        v1 -= v2;
+        assert_eq!(v1, Vector::new(1, 2));
+    }
+
+    #[test]
+    fn vector_multiply_negate() {
+        let v = Vector::new(1, -2);
+
+        assert_eq!(v * 3, Vector::new(3, -6));
+        assert_eq!(-v, Vector::new(-1, 2));
+    }
+
+    #[test]
+    fn vector_dot() {
+        assert_eq!(Vector::new(1, 2).dot(Vector::new(3, 4)), 11);
+    }
+
+    #[test]
+    fn vector_cross() {
+        assert_eq!(Vector::new(1, 0).cross(Vector::new(0, 1)), 1);
+        assert_eq!(Vector::new(0, 1).cross(Vector::new(1, 0)), -1);
+    }
+
+    #[test]
+    fn vector_perp_and_rotate() {
+        let v = Vector::new(1, 0);
+
+        assert_eq!(v.perp(), Vector::new(0, 1));
+        assert_eq!(v.rotate_cw(), Vector::new(0, 1));
+        assert_eq!(v.rotate_ccw(), Vector::new(0, -1));
+        assert_eq!(v.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), v);
+    }
+}